@@ -1,5 +1,5 @@
 use core::str;
-use std::{fs::File, path::Path};
+use std::{fs::File, path::Path, sync::OnceLock};
 
 use memmap2::Mmap;
 
@@ -13,6 +13,7 @@ pub struct NxFile {
     pub(crate) data: Mmap,
     pub(crate) header: NxHeader,
     pub(crate) root: NxNodeData,
+    pub(crate) string_cache: Option<Box<[OnceLock<Box<str>>]>>,
 }
 
 impl NxFile {
@@ -28,7 +29,32 @@ impl NxFile {
         let header = NxHeader::new(&data)?;
         let root = data.try_get_node_data(header.node_offset)?;
 
-        Ok(Self { data, header, root })
+        Ok(Self {
+            data,
+            header,
+            root,
+            string_cache: None,
+        })
+    }
+
+    /// Enables a string cache that is shared between all nodes of the file.
+    ///
+    /// Strings are decoded at most once and then reused on subsequent lookups. The cache is
+    /// thread-safe, so a file shared between threads can still be traversed concurrently.
+    ///
+    /// Each cached string is copied out of the memory map onto the heap, so the cache only saves
+    /// the UTF-8 validation of repeated lookups at the cost of an allocation per distinct string.
+    /// It's worth enabling when the same names are resolved many times, e.g. hot lookup paths
+    /// shared across threads, but not for one-off traversals. Strings returned while the cache is
+    /// enabled borrow from the cache instead of the memory map.
+    pub fn with_shared_string_cache(mut self) -> Self {
+        self.string_cache = Some(
+            (0..self.header.string_count)
+                .map(|_| OnceLock::new())
+                .collect(),
+        );
+
+        self
     }
 
     /// Gets the total number of nodes in the file.
@@ -52,7 +78,7 @@ impl NxFile {
     }
 
     /// Gets the root node.
-    pub fn root(&self) -> NxNode {
+    pub fn root(&self) -> NxNode<'_> {
         NxNode {
             data: self.root,
             file: self,
@@ -61,12 +87,34 @@ impl NxFile {
 
     /// Gets a string from the file at the given index.
     pub(crate) fn get_str(&self, index: u32) -> Result<&str, NxError> {
+        let cached = self
+            .string_cache
+            .as_ref()
+            .and_then(|cache| cache.get(index as usize));
+
+        match cached {
+            Some(cell) => {
+                if let Some(string) = cell.get() {
+                    return Ok(string);
+                }
+
+                // Another thread may have populated the cell in the meantime, in which case its
+                // value is kept and ours is dropped.
+                let string = self.read_str(index)?;
+                Ok(cell.get_or_init(|| string.into()))
+            }
+            None => self.read_str(index),
+        }
+    }
+
+    /// Reads a string from the file at the given index, bypassing the string cache.
+    fn read_str(&self, index: u32) -> Result<&str, NxError> {
         let offset = self
             .data
             .try_get_u64(self.header.string_offset + (index as u64 * size_of::<u64>() as u64))?;
 
         let len = self.data.try_get_u16(offset)?;
-        Ok(self.data.try_get_str(offset + 2, len)?)
+        self.data.try_get_str(offset + 2, len)
     }
 
     /// Gets a bitmap from the file at the given index.
@@ -76,7 +124,7 @@ impl NxFile {
             .try_get_u64(self.header.bitmap_offset + (index as u64 * size_of::<u64>() as u64))?;
 
         let len = self.data.try_get_u32(offset)?;
-        Ok(self.data.try_get_bytes(offset + 4, len as usize)?)
+        self.data.try_get_bytes(offset + 4, len as usize)
     }
 }
 
@@ -88,6 +136,9 @@ pub(crate) struct NxHeader {
     bitmap_count: u32,
     pub(crate) bitmap_offset: u64,
     audio_count: u32,
+    // Audio nodes can't be read yet, so nothing uses the audio table's offset. It's still parsed
+    // so the header stays complete for when audio support is added.
+    #[allow(dead_code)]
     pub(crate) audio_offset: u64,
 }
 
@@ -114,6 +165,7 @@ impl NxHeader {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Node;
 
     #[test]
     fn open_file_does_not_exist() {
@@ -139,4 +191,39 @@ mod tests {
         assert_eq!(file.bitmap_count(), 0);
         assert_eq!(file.audio_count(), 0);
     }
+
+    #[test]
+    fn shared_string_cache_across_threads() {
+        let file = NxFile::open(Path::new("data/valid.nx"))
+            .unwrap()
+            .with_shared_string_cache();
+
+        std::thread::scope(|scope| {
+            for name in ["100", "95"].into_iter().cycle().take(8) {
+                let file = &file;
+
+                scope.spawn(move || {
+                    for _ in 0..2 {
+                        let root = file.root();
+                        let img = root.get("StandardPDD.img");
+                        let parent = img.get("1");
+                        let node = parent.get(name).unwrap();
+                        assert_eq!(node.name().unwrap(), name);
+                    }
+                });
+            }
+        });
+
+        let cache = file.string_cache.as_ref().unwrap();
+        assert_eq!(cache.len(), file.string_count() as usize);
+
+        let root = file.root();
+        let img = root.get("StandardPDD.img");
+        let parent = img.get("1");
+
+        for name in ["100", "95"] {
+            let index = parent.get(name).unwrap().data.name as usize;
+            assert_eq!(cache[index].get().map(AsRef::as_ref), Some(name));
+        }
+    }
 }
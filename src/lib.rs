@@ -4,7 +4,7 @@ use node::NxNodeData;
 use thiserror::Error;
 
 pub use self::file::NxFile;
-pub use self::node::{Node, NxNode, NxNodeIterator, NxNodeType};
+pub use self::node::{Node, NxNode, NxNodeIterator, NxNodeType, DEFAULT_DELAY_MS};
 
 pub mod file;
 pub mod node;
@@ -55,14 +55,9 @@ pub(crate) trait NxTryGet {
 impl NxTryGet for [u8] {
     fn try_get_bytes(&self, index: u64, len: usize) -> Result<&[u8], NxError> {
         let usize_index = index as usize;
-        let usize_len = len as usize;
-
-        Ok(self
-            .get(usize_index..usize_index + usize_len)
-            .ok_or(NxError::OutOfBoundsRange(
-                usize_index,
-                usize_index + usize_len,
-            ))?)
+
+        self.get(usize_index..usize_index + len)
+            .ok_or(NxError::OutOfBoundsRange(usize_index, usize_index + len))
     }
 
     fn try_get_u16(&self, index: u64) -> Result<u16, NxError> {
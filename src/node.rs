@@ -6,6 +6,9 @@ use crate::{file::NxFile, NxBitmap, NxError, NxTryGet};
 
 const NX_NODE_OFFSET: u64 = 20;
 
+/// The frame delay, in milliseconds, used when a node has no `delay` child.
+pub const DEFAULT_DELAY_MS: i64 = 100;
+
 #[derive(Copy, Clone)]
 pub(crate) struct NxNodeData {
     pub(crate) index: u64,
@@ -24,7 +27,7 @@ pub struct NxNode<'a> {
 
 impl<'a> NxNode<'a> {
     /// Gets a node with the given name starting from the current node.
-    fn get(&self, name: &str) -> Option<NxNode> {
+    fn get(&self, name: &str) -> Option<NxNode<'_>> {
         let mut index = self.file.header.node_offset + self.data.children as u64 * NX_NODE_OFFSET;
         let mut count = self.data.count as u64;
 
@@ -103,8 +106,38 @@ impl<'a> NxNode<'a> {
         }
     }
 
+    /// Gets the frame delay, in milliseconds, from the node's `delay` child.
+    ///
+    /// Integer delays are returned as is, float delays are truncated, and string delays are
+    /// parsed as integers. Returns `None` if the node has no `delay` child, the child has any other
+    /// type, or a string delay isn't a valid integer.
+    pub fn delay_ms(&self) -> Result<Option<i64>, NxError> {
+        let delay = match self.get("delay") {
+            Some(delay) => delay,
+            None => return Ok(None),
+        };
+
+        match delay.data.data_type {
+            NxNodeType::Integer => Ok(Some(delay.data.data as i64)),
+            NxNodeType::Float => Ok(Some(f64::from_bits(delay.data.data) as i64)),
+            NxNodeType::String => {
+                let value = self.file.get_str(delay.data.data as u32)?;
+                Ok(value.trim().parse().ok())
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Gets the frame delay, in milliseconds, falling back to [`DEFAULT_DELAY_MS`].
+    ///
+    /// The default is also used whenever [`NxNode::delay_ms`] returns `None`, e.g. for a `delay`
+    /// child that isn't a number.
+    pub fn delay_or_default(&self) -> Result<i64, NxError> {
+        Ok(self.delay_ms()?.unwrap_or(DEFAULT_DELAY_MS))
+    }
+
     /// Gets an iterator over the node's children.
-    pub fn iter(&self) -> Result<NxNodeIterator, NxError> {
+    pub fn iter(&self) -> Result<NxNodeIterator<'_>, NxError> {
         let data = self.file.data.try_get_node_data(
             self.file.header.node_offset + self.data.children as u64 * NX_NODE_OFFSET,
         )?;
@@ -146,13 +179,13 @@ impl From<u16> for NxNodeType {
 }
 
 pub trait Node {
-    fn get(&self, name: &str) -> Option<NxNode>;
+    fn get(&self, name: &str) -> Option<NxNode<'_>>;
 
     fn bitmap(&self) -> Result<Option<NxBitmap>, NxError>;
 }
 
 impl<'a> Node for NxNode<'a> {
-    fn get(&self, name: &str) -> Option<NxNode> {
+    fn get(&self, name: &str) -> Option<NxNode<'_>> {
         self.get(name)
     }
 
@@ -162,7 +195,7 @@ impl<'a> Node for NxNode<'a> {
 }
 
 impl<'a> Node for Option<NxNode<'a>> {
-    fn get(&self, name: &str) -> Option<NxNode> {
+    fn get(&self, name: &str) -> Option<NxNode<'_>> {
         match self {
             Some(node) => node.get(name),
             None => None,
@@ -219,3 +252,42 @@ impl<'a> Iterator for NxNodeIterator<'a> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::*;
+
+    #[test]
+    fn delay_of_frame_without_delay() {
+        let file = NxFile::open(Path::new("data/valid.nx")).unwrap();
+        let root = file.root();
+        let img = root.get("StandardPDD.img");
+        let frame = img.get("0").unwrap();
+
+        assert_eq!(frame.delay_ms().unwrap(), None);
+        assert_eq!(frame.delay_or_default().unwrap(), DEFAULT_DELAY_MS);
+    }
+
+    #[test]
+    fn delay_of_frames() {
+        let file = NxFile::open(Path::new("data/delay.nx")).unwrap();
+        let root = file.root();
+
+        // Integer, float, string, unparsable string and missing delays respectively.
+        let expected = [
+            ("0", Some(120), 120),
+            ("1", Some(90), 90),
+            ("2", Some(150), 150),
+            ("3", None, DEFAULT_DELAY_MS),
+            ("4", None, DEFAULT_DELAY_MS),
+        ];
+
+        for (name, delay, delay_or_default) in expected {
+            let frame = root.get(name).unwrap();
+            assert_eq!(frame.delay_ms().unwrap(), delay);
+            assert_eq!(frame.delay_or_default().unwrap(), delay_or_default);
+        }
+    }
+}